@@ -0,0 +1,125 @@
+//! Module with both safe and unsafe implementations of [elementary column operation] of column addition
+//!
+//! [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+
+use crate::{
+    elem_col_op::ElemColOp, err::BinaryColIdxOutOfBoundsError, MatrixReprOfLinSys,
+};
+use core::ops::{AddAssign, Mul};
+use nalgebra::{Dim, RawStorageMut, Matrix};
+
+/// The type representing the [elementary column operation] of column addition, i.e. the
+/// operation on a matrix where the multiple of one column is added entrywise to another
+/// column.
+///
+/// [Functionally defined], it is one of possible [parameter objects] for
+/// [`MatrixReprOfLinSys::perform_elem_col_op`][`crate::MatrixReprOfLinSys::perform_elem_col_op`].
+///
+/// # Generic arguments
+///
+/// `'a` - the lifetime of the `factor`;
+///
+/// `T` - the type of the `factor`.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::matrix;
+/// use nalgebra_linsys::{
+///     MatrixReprOfLinSys as MRLS,
+///     elem_col_ops::ColAdd,
+/// };
+///
+/// let mut m = MRLS::new(matrix![
+///    1, 2, 3;
+///    2, 0, 4;
+/// ]);
+///
+/// m.perform_elem_col_op(ColAdd {
+///    inout_col_zbi: 1,
+///    in_col_zbi: 0,
+///    factor: &-2
+/// }).unwrap();
+///
+/// assert_eq!(
+///    m.0,
+///    matrix![
+///      1,  0, 3;
+///      2, -4, 4;
+/// ]);
+/// ```
+///
+/// [Functionally defined]: https://www.ucfmapper.com/education/various-types-definitions/#:~:text=Functional%20definitions
+/// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+/// [parameter objects]: http://principles-wiki.net/patterns:parameter_object
+pub struct ColAdd<'a, T> {
+    /// The zero-based index of the column to which the scaled second column is added, i.e.
+    /// the zero-based index of the "inout column"
+    pub inout_col_zbi: usize,
+    /// The zero-based index of the column to be scaled and added to the "inout column", i.e.
+    /// the zero-based index of the "in column"
+    pub in_col_zbi: usize,
+    /// The factor by which the "in column" is scaled before summation
+    pub factor: &'a T,
+}
+
+impl<'a, T, R, C, S> ElemColOp<Matrix<T,R,C,S>> for ColAdd<'a, T>
+where
+    T: Clone + AddAssign + Mul<&'a T, Output = T>,
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    type Error = BinaryColIdxOutOfBoundsError;
+
+    unsafe fn perform_unchecked(self, m: &mut Matrix<T, R, C, S>) {
+        let ColAdd {
+            inout_col_zbi: j_1,
+            in_col_zbi: j_2,
+            factor,
+        } = self;
+
+        let nrows = m.nrows();
+
+        for i in 0..nrows {
+            let corresponding_entry = m[(i, j_2)].to_owned();
+            *m.get_unchecked_mut((i, j_1)) += corresponding_entry * factor;
+        }
+    }
+
+    fn validate(&self, m: &Matrix<T, R, C, S>) -> Result<(), Self::Error> {
+        use BinaryColIdxOutOfBoundsError::*;
+
+        let ColAdd {
+            inout_col_zbi: j_1,
+            in_col_zbi: j_2,
+            factor: _unused_factor,
+        } = *self;
+
+        let ncols = m.ncols();
+        match (j_1, j_2) {
+            (j_1, j_2) if j_1 >= ncols && j_2 >= ncols => Err(BothIdcesOutOfBounds((j_1, j_2))),
+            (j_1, j_2) if j_1 >= ncols => Err(FirstIdxOutOfBounds((j_1, j_2))),
+            (j_1, j_2) if j_2 >= ncols => Err(SecondIdxOutOfBounds((j_1, j_2))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a, T, R, C, S> ElemColOp<MatrixReprOfLinSys<T,R,C,S>> for ColAdd<'a, T>
+where
+    T: Clone + AddAssign + Mul<&'a T, Output = T>,
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    type Error = BinaryColIdxOutOfBoundsError;
+
+    unsafe fn perform_unchecked(self, m: &mut MatrixReprOfLinSys<T,R,C,S>) {
+        self.perform_unchecked(&mut m.0)
+    }
+
+    fn validate(&self, m: &MatrixReprOfLinSys<T,R,C,S>) -> Result<(), Self::Error> {
+        self.validate(&m.0)
+    }
+}
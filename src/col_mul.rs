@@ -0,0 +1,104 @@
+//! Module with both safe and unsafe implementations of [elementary column operation] of column multiplication
+//!
+//! [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+
+use crate::{elem_col_op::ElemColOp, err::ColIdxOutOfBoundsError, MatrixReprOfLinSys};
+use core::ops::MulAssign;
+use nalgebra::{Dim, RawStorageMut, Matrix};
+
+/// The type representing the [elementary column operation] of column multiplication, i.e.
+/// the operation on a matrix where one column is scaled by the same factor in every entry.
+///
+/// [Functionally defined], it is one of possible [parameter objects] for
+/// [`MatrixReprOfLinSys::perform_elem_col_op`][`crate::MatrixReprOfLinSys::perform_elem_col_op`].
+///
+/// # Generic arguments
+///
+/// `'a` - the lifetime of the `factor`;
+///
+/// `T` - the type of the `factor`.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::matrix;
+/// use nalgebra_linsys::{
+///     MatrixReprOfLinSys,
+///     elem_col_ops::ColMul,
+/// };
+///
+/// let mut m = MatrixReprOfLinSys::new(matrix![
+///    1, 2, 3;
+///    2, 4, 5;
+/// ]);
+///
+/// m.perform_elem_col_op(ColMul {
+///   col_zbi: 0,
+///   factor: &2
+/// }).unwrap();
+///
+/// assert_eq!(
+///    m.0,
+///    matrix![
+///      2, 2, 3;
+///      4, 4, 5;
+/// ]);
+/// ```
+///
+/// [parameter objects]: http://principles-wiki.net/patterns:parameter_object
+/// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+/// [Functionally defined]: https://www.ucfmapper.com/education/various-types-definitions/#:~:text=Functional%20definitions
+pub struct ColMul<'a, T> {
+    /// The zero-based index of the column to be scaled
+    pub col_zbi: usize,
+    /// The factor by which the column is scaled
+    pub factor: &'a T,
+}
+
+impl<'a, T, R, C, S> ElemColOp<Matrix<T,R,C,S>> for ColMul<'a, T>
+where
+    T: Clone + MulAssign<&'a T>,
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    type Error = ColIdxOutOfBoundsError;
+
+    unsafe fn perform_unchecked(self, m: &mut Matrix<T, R, C, S>) {
+        let ColMul { col_zbi: j, factor } = self;
+
+        let nrows = m.nrows();
+        for i in 0..nrows {
+            *m.get_unchecked_mut((i, j)) *= factor;
+        }
+    }
+
+    fn validate(&self, m: &Matrix<T, R, C, S>) -> Result<(), Self::Error> {
+        let col_zero_based_idx = self.col_zbi;
+        let ncols = m.ncols();
+
+        if col_zero_based_idx >= ncols {
+            Err(ColIdxOutOfBoundsError(col_zero_based_idx))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a, T, R, C, S> ElemColOp<MatrixReprOfLinSys<T,R,C,S>> for ColMul<'a, T>
+where
+    T: Clone + MulAssign<&'a T>,
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    type Error = ColIdxOutOfBoundsError;
+
+    unsafe fn perform_unchecked(self, m: &mut MatrixReprOfLinSys<T,R,C,S>) {
+        self.perform_unchecked(&mut m.0)
+    }
+
+    fn validate(&self, m: &MatrixReprOfLinSys<T,R,C,S>) -> Result<(), Self::Error> {
+        self.validate(&m.0)
+    }
+}
@@ -0,0 +1,92 @@
+//! Module with both safe and unsafe implementations of [elementary column operation] of column exchange
+//!
+//! [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+
+use crate::{
+    elem_col_op::ElemColOp, err::BinaryColIdxOutOfBoundsError, MatrixReprOfLinSys,
+};
+use nalgebra::{Dim, RawStorageMut};
+
+/// The type representing the [elementary column operation] of column exchange, i.e. the
+/// operation on a matrix that swaps entries in two of its columns.
+///
+/// [Functionally defined], it is one of possible [parameter objects] for
+/// [`MatrixReprOfLinSys::perform_elem_col_op`][`crate::MatrixReprOfLinSys::perform_elem_col_op`].
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::matrix;
+/// use nalgebra_linsys::{
+///    MatrixReprOfLinSys as MRLS,
+///    elem_col_ops::ColXchg,
+/// };
+///
+/// let mut m = MRLS::new(matrix![
+///   1, 2, 3;
+///   4, 5, 6;
+/// ]);
+///
+/// m.perform_elem_col_op(ColXchg {
+///     col_zbi_1: 1,
+///     col_zbi_2: 0,
+/// }).unwrap();
+///
+/// assert_eq!(
+///  m.0,
+///  matrix![
+///    2, 1, 3;
+///    5, 4, 6;
+/// ]);
+/// ```
+///
+/// [Functionally defined]: https://www.ucfmapper.com/education/various-types-definitions/#:~:text=Functional%20definitions
+/// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+/// [parameter objects]: http://principles-wiki.net/patterns:parameter_object
+pub struct ColXchg {
+    /// The zero-based index of the first column to be exchanged
+    pub col_zbi_1: usize,
+    /// The zero-based index of the second column to be exchanged
+    pub col_zbi_2: usize,
+}
+
+impl<T, R, C, S> ElemColOp<MatrixReprOfLinSys<T,R,C,S>> for ColXchg
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    type Error = BinaryColIdxOutOfBoundsError;
+
+    unsafe fn perform_unchecked(self, m: &mut MatrixReprOfLinSys<T, R, C, S>) {
+        let ColXchg {
+            col_zbi_1: j_1,
+            col_zbi_2: j_2,
+        } = self;
+
+        let nrows = m.0.nrows();
+        (0..nrows)
+            .map(|i| ((i, j_1), (i, j_2)))
+            .for_each(|(row_col1, row_col2)| {
+                m.0.swap_unchecked(row_col1, row_col2);
+            });
+    }
+
+    fn validate(&self, m: &MatrixReprOfLinSys<T, R, C, S>) -> Result<(), Self::Error> {
+        use BinaryColIdxOutOfBoundsError::*;
+
+        let ColXchg {
+            col_zbi_1: j_1,
+            col_zbi_2: j_2,
+        } = *self;
+
+        let ncols = m.0.ncols();
+
+        match (j_1, j_2) {
+            (j_1, j_2) if j_1 >= ncols && j_2 >= ncols => Err(BothIdcesOutOfBounds((j_1, j_2))),
+            (j_1, j_2) if j_1 >= ncols => Err(FirstIdxOutOfBounds((j_1, j_2))),
+            (j_1, j_2) if j_2 >= ncols => Err(SecondIdxOutOfBounds((j_1, j_2))),
+            _ => Ok(()),
+        }
+    }
+}
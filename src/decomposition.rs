@@ -0,0 +1,156 @@
+//! Module with lightweight [decomposition]-style methods on [`MatrixReprOfLinSys`] — namely
+//! [`determinant`][MatrixReprOfLinSys::determinant] and
+//! [`try_inverse`][MatrixReprOfLinSys::try_inverse] — both implemented through the elementary
+//! row operation pipeline that also backs [reduced row echelon form].
+//!
+//! [decomposition]: https://en.wikipedia.org/wiki/Matrix_decomposition
+//! [reduced row echelon form]: crate::MatrixReprOfLinSys::reduced_row_echelon_form
+
+use crate::MatrixReprOfLinSys;
+use core::ops::{Mul, MulAssign};
+use nalgebra::{allocator::Allocator, DMatrix, DefaultAllocator, Dim, OMatrix, RealField, Storage};
+
+impl<T, R, C, S> MatrixReprOfLinSys<T, R, C, S>
+where
+    T: RealField,
+    for<'b> T: Mul<&'b T, Output = T> + MulAssign<&'b T>,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Computes the determinant of the (square) matrix represented by `self`.
+    ///
+    /// This drives the same forward-elimination pivoting as
+    /// [`reduced_row_echelon_form`][Self::reduced_row_echelon_form] on an owned copy of the
+    /// matrix and folds the determinant-changing effect of each elementary operation into a
+    /// running accumulator. If a full pivot column cannot be found for every row (i.e. the
+    /// matrix is singular), the determinant is `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not a square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::matrix;
+    /// use nalgebra_linsys::MatrixReprOfLinSys as MRLS;
+    ///
+    /// let m = MRLS::new(matrix![
+    ///    1.0, 2.0;
+    ///    3.0, 4.0;
+    /// ]);
+    ///
+    /// assert_eq!(m.determinant(), -2.0);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// `determinant` and `try_inverse` each reduce their own owned copy of the matrix, so
+    /// calling both throws away the elimination the other one already did. A caller that
+    /// wants the pivot columns (the rank) *and* the sign/scale accumulator from the very same
+    /// pass can call [`reduce_tracking_determinant`][Self::reduce_tracking_determinant]
+    /// directly instead of going through `determinant`:
+    ///
+    /// ```
+    /// use nalgebra::matrix;
+    /// use nalgebra_linsys::MatrixReprOfLinSys as MRLS;
+    ///
+    /// let mut m = MRLS::new(matrix![
+    ///    1.0, 2.0;
+    ///    3.0, 4.0;
+    /// ]);
+    ///
+    /// let (pivots, acc) = m.reduce_tracking_determinant();
+    ///
+    /// assert_eq!(pivots, vec![0, 1]);
+    /// assert_eq!(acc, -2.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        assert_eq!(
+            self.0.nrows(),
+            self.0.ncols(),
+            "Unable to compute the determinant of a non-square matrix."
+        );
+
+        let nrows = self.0.nrows();
+        let mut working = MatrixReprOfLinSys::new(self.0.clone_owned());
+        let (pivots, det) = working.reduce_tracking_determinant();
+
+        if pivots.len() == nrows {
+            det
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Attempts to compute the inverse of the (square) matrix represented by `self`, or
+    /// `None` if it is singular.
+    ///
+    /// The square matrix is augmented with an identity block of the same dimension, and the
+    /// combined augmentation is driven through
+    /// [`reduced_row_echelon_form`][Self::reduced_row_echelon_form]: if every row obtains a
+    /// pivot in the original (left) block, the right-hand block left behind by the reduction
+    /// is the inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not a square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::relative_eq;
+    /// use nalgebra::matrix;
+    /// use nalgebra_linsys::MatrixReprOfLinSys as MRLS;
+    ///
+    /// let m = MRLS::new(matrix![
+    ///    1.0, 2.0;
+    ///    3.0, 4.0;
+    /// ]);
+    ///
+    /// // The elimination this is built on accumulates floating-point error, so the result is
+    /// // only approximately exact; compare with a tolerance rather than `assert_eq!`.
+    /// assert!(relative_eq!(
+    ///    m.try_inverse().unwrap(),
+    ///    matrix![
+    ///      -2.0,  1.0;
+    ///       1.5, -0.5;
+    ///    ],
+    ///    epsilon = 1e-9
+    /// ));
+    /// ```
+    pub fn try_inverse(&self) -> Option<OMatrix<T, R, C>> {
+        assert_eq!(
+            self.0.nrows(),
+            self.0.ncols(),
+            "Unable to compute the inverse of a non-square matrix."
+        );
+
+        let n = self.0.nrows();
+        let augmented = DMatrix::from_fn(n, 2 * n, |i, j| {
+            if j < n {
+                self.0[(i, j)].clone()
+            } else if j - n == i {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+
+        let mut working = MatrixReprOfLinSys::new(augmented);
+        let (pivots, _) = working.reduce_tracking_determinant();
+
+        // The identity block always supplies `n` pivots, so a singular left block shows up as
+        // a pivot column at index `n` or beyond rather than as a short pivot list.
+        if pivots.len() != n || pivots.iter().any(|&col| col >= n) {
+            return None;
+        }
+
+        let (r_dim, c_dim) = self.0.shape_generic();
+        Some(OMatrix::from_fn_generic(r_dim, c_dim, |i, j| {
+            working.0[(i, n + j)].clone()
+        }))
+    }
+}
@@ -0,0 +1,54 @@
+//! Module with [parameter objects] for both safe and unsafe implementations of [elementary column operations]
+//!
+//! [elementary column operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+//! [parameter objects]: https://en.wikipedia.org/wiki/Parameter_object
+
+/// The trait whose implementors represent [elementary column operations][eco] acting on a
+/// given structure (for example, on a matrix).
+///
+/// Column analogue of [`ElemRowOp`][crate::elem_row_op::ElemRowOp], with the same split
+/// between the validating [`perform`][ElemColOp::perform] and the unchecked
+/// [`perform_unchecked`][ElemColOp::perform_unchecked].
+///
+/// # Generic arguments
+///
+/// `T` - the type on which the [elementary column operations][eco] act.
+///
+/// [eco]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+/// [parameter objects]: http://principles-wiki.net/patterns:parameter_object
+pub trait ElemColOp<T>: Sized {
+    type Error;
+    /// Performs the [elementary column operation] on the given structure without validation
+    /// of the internal state describing the operation, such as bounds checking of the
+    /// indices of columns.
+    ///
+    /// # Arguments
+    ///
+    /// `m` - the matrix or any other structure on which the [elementary column operation] is
+    /// to be performed.
+    ///
+    /// # Safety
+    ///
+    /// [`ElemColOp::validate`] must be executed successfully before performing the operation.
+    ///
+    /// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    unsafe fn perform_unchecked(self, m: &mut T);
+    /// Checks whether the internal state describing the [elementary column operation] is
+    /// valid. For example, the validation may include bounds checking of the column indices.
+    ///
+    /// # Arguments
+    ///
+    /// `m` - the matrix or any other structure on which the [elementary column operation] is
+    /// to be performed.
+    ///
+    /// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    fn validate(&self, m: &T) -> Result<(), Self::Error>;
+    /// Performs the [elementary column operation] on the given structure.
+    ///
+    /// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    fn perform(self, m: &mut T) -> Result<(), Self::Error> {
+        self.validate(m)?;
+        unsafe { self.perform_unchecked(m) };
+        Ok(())
+    }
+}
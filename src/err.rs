@@ -34,3 +34,51 @@ pub enum BinaryRowIdxOutOfBoundsError {
 #[derive(Error, Debug)]
 #[error("Row index is out of bounds: {0:?}")]
 pub struct RowIdxOutOfBoundsError(pub(crate) usize);
+
+/// Error type for [elementary row operations] acting on sparse (CSR/CSC) matrices.
+///
+/// # Notes
+///
+/// Sparse operations only ever fail the same way the dense implementations can: an out-of-
+/// bounds row index. Rebuilding the compressed storage after a [`RowXchg`][crate::elem_row_ops::RowXchg]
+/// or [`RowAdd`][crate::elem_row_ops::RowAdd] cannot itself fail, since it is always driven
+/// from a matrix that `nalgebra_sparse` already guarantees is a valid CSR/CSC structure.
+///
+/// [elementary row operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+#[cfg(feature = "sparse")]
+#[derive(Error, Debug)]
+pub enum SparseRowOpError {
+    #[error(transparent)]
+    RowIdxOutOfBounds(#[from] RowIdxOutOfBoundsError),
+    #[error(transparent)]
+    BinaryRowIdxOutOfBounds(#[from] BinaryRowIdxOutOfBoundsError),
+}
+
+/// Out-of-bounds error type for [`ColXchg`] and [`ColAdd`].
+///
+/// # Notes
+///
+/// Column analogue of [`BinaryRowIdxOutOfBoundsError`].
+///
+/// [`ColXchg`]: crate::elem_col_ops::ColXchg
+/// [`ColAdd`]: crate::elem_col_ops::ColAdd
+#[derive(Error, Debug)]
+pub enum BinaryColIdxOutOfBoundsError {
+    #[error("First column index is out of bounds: {0:?}")]
+    FirstIdxOutOfBounds((usize, usize)),
+    #[error("Second column index is out of bounds: {0:?}")]
+    SecondIdxOutOfBounds((usize, usize)),
+    #[error("Both column indices are out of bounds: {0:?}")]
+    BothIdcesOutOfBounds((usize, usize)),
+}
+
+/// Out-of-bounds error type for [`ColMul`].
+///
+/// # Notes
+///
+/// Column analogue of [`RowIdxOutOfBoundsError`].
+///
+/// [`ColMul`]: crate::elem_col_ops::ColMul
+#[derive(Error, Debug)]
+#[error("Column index is out of bounds: {0:?}")]
+pub struct ColIdxOutOfBoundsError(pub(crate) usize);
@@ -4,14 +4,27 @@ extern crate num_bigint;
 extern crate num_rational;
 
 pub mod err;
+mod col_add;
+mod col_mul;
+mod col_xchg;
+mod decomposition;
+mod elem_col_op;
 mod elem_row_op;
+mod recording;
 mod row_add;
 mod row_mul;
 mod row_xchg;
+mod rref;
+#[cfg(feature = "sparse")]
+mod sparse;
+mod solve;
 
 use elem_row_op::ElemRowOp;
 use nalgebra::Matrix;
 
+pub use recording::{RecordingMatrix, ReplayError, RowOpRecord};
+pub use solve::SolutionSet;
+
 /// Module with types representing [elementary row operations], namely row addition, row exchange, and row multiplication
 /// 
 /// [elementary row operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
@@ -22,6 +35,18 @@ pub mod elem_row_ops {
     pub use crate::elem_row_op::ElemRowOp;
 }
 
+/// Module with types representing [elementary column operations], the column analogues of
+/// [elementary row operations][crate::elem_row_ops], namely column addition, column
+/// exchange, and column multiplication.
+///
+/// [elementary column operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+pub mod elem_col_ops {
+    pub use crate::col_add::ColAdd;
+    pub use crate::col_mul::ColMul;
+    pub use crate::col_xchg::ColXchg;
+    pub use crate::elem_col_op::ElemColOp;
+}
+
 /// [Matrix representation of a linear system][MRLS].
 ///
 /// # Example
@@ -142,3 +167,18 @@ impl<T, R, C, S> MatrixReprOfLinSys<T, R, C, S> {
         o.perform(self)
     }
 }
+
+impl<T, R, C, S> MatrixReprOfLinSys<T, R, C, S> {
+    /// Performs the given [elementary column operation] on the matrix representation of the linear system.
+    ///
+    /// For examples, refer to the documentation of [`ColXchg`][crate::elem_col_ops::ColXchg],
+    /// [`ColAdd`][crate::elem_col_ops::ColAdd], and/or [`ColMul`][crate::elem_col_ops::ColMul].
+    ///
+    /// [elementary column operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    pub fn perform_elem_col_op<O>(&mut self, o: O) -> Result<(), O::Error>
+    where
+        O: elem_col_op::ElemColOp<Self>,
+    {
+        o.perform(self)
+    }
+}
@@ -0,0 +1,277 @@
+//! Module with [`RecordingMatrix`], a decorator that wraps any structure [`ElemRowOp`] acts
+//! on and transcribes every performed operation, and [`RowOpRecord`], the normalized
+//! description of a single transcribed operation.
+//!
+//! This is the first-class version of what the [`ElemRowOp`] docs already suggest users
+//! might implement for their own type: "to output intermediate results in the chain of
+//! transformations".
+
+use crate::{
+    elem_row_op::ElemRowOp,
+    row_add::RowAdd,
+    row_mul::RowMul,
+    row_xchg::RowXchg,
+};
+use thiserror::Error;
+
+/// A normalized, owned description of a single performed [elementary row operation].
+///
+/// [elementary row operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowOpRecord<T> {
+    /// A [`RowXchg`] of the two given zero-based row indices.
+    RowXchg { row_zbi_1: usize, row_zbi_2: usize },
+    /// A [`RowMul`] of the given zero-based row index by `factor`.
+    RowMul { row_zbi: usize, factor: T },
+    /// A [`RowAdd`] of `factor` times the "in row" into the "inout row", both given as
+    /// zero-based row indices.
+    RowAdd {
+        inout_row_zbi: usize,
+        in_row_zbi: usize,
+        factor: T,
+    },
+}
+
+/// Error type for [`RecordingMatrix::replay`], unifying the distinct error types of the
+/// [`RowXchg`], [`RowMul`], and [`RowAdd`] operations being replayed onto the target
+/// structure.
+#[derive(Error, Debug)]
+pub enum ReplayError<EXchg, EMul, EAdd> {
+    #[error(transparent)]
+    RowXchg(EXchg),
+    #[error(transparent)]
+    RowMul(EMul),
+    #[error(transparent)]
+    RowAdd(EAdd),
+}
+
+/// The return type of [`RecordingMatrix::replay`], spelled out as an alias since the error
+/// variants are themselves associated types of the three elementary row operations being
+/// replayed onto `M2`.
+type ReplayResult<'s, T, M2> = Result<
+    (),
+    ReplayError<
+        <RowXchg as ElemRowOp<M2>>::Error,
+        <RowMul<'s, T> as ElemRowOp<M2>>::Error,
+        <RowAdd<'s, T> as ElemRowOp<M2>>::Error,
+    >,
+>;
+
+/// A decorator that wraps a structure `M` (for example, a [`MatrixReprOfLinSys`][crate::MatrixReprOfLinSys])
+/// and, for every [elementary row operation] performed on it through [`ElemRowOp`], pushes a
+/// normalized [`RowOpRecord`] describing that operation into an ordered log.
+///
+/// The log can be replayed onto a different, compatible structure with [`replay`][Self::replay],
+/// which is enough to rebuild the product elementary matrix of a chain of operations, or to
+/// audit/step through a solve.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::matrix;
+/// use nalgebra_linsys::{
+///     MatrixReprOfLinSys as MRLS,
+///     RecordingMatrix,
+///     RowOpRecord,
+///     elem_row_ops::{RowAdd, RowMul},
+/// };
+///
+/// let mut recording = RecordingMatrix::new(MRLS::new(matrix![
+///    1, 2, 3;
+///    2, 4, 8;
+/// ]));
+///
+/// recording.perform_elem_row_op(RowAdd {
+///     inout_row_zbi: 1,
+///     in_row_zbi: 0,
+///     factor: &-2,
+/// }).unwrap();
+///
+/// recording.perform_elem_row_op(RowMul {
+///     row_zbi: 1,
+///     factor: &2,
+/// }).unwrap();
+///
+/// assert_eq!(
+///     recording.log,
+///     vec![
+///         RowOpRecord::RowAdd { inout_row_zbi: 1, in_row_zbi: 0, factor: -2 },
+///         RowOpRecord::RowMul { row_zbi: 1, factor: 2 },
+///     ],
+/// );
+///
+/// let mut replayed = MRLS::new(matrix![
+///    1, 2, 3;
+///    2, 4, 8;
+/// ]);
+/// recording.replay(&mut replayed).unwrap();
+///
+/// assert_eq!(replayed.0, recording.inner.0);
+/// ```
+///
+/// [elementary row operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+pub struct RecordingMatrix<T, M> {
+    /// The wrapped structure that elementary row operations are actually performed on.
+    pub inner: M,
+    /// The ordered log of every elementary row operation performed on `inner` so far.
+    pub log: Vec<RowOpRecord<T>>,
+}
+
+impl<T, M> RecordingMatrix<T, M> {
+    /// Wraps `inner` in a new [`RecordingMatrix`] with an empty log.
+    pub fn new(inner: M) -> Self {
+        RecordingMatrix {
+            inner,
+            log: Vec::new(),
+        }
+    }
+
+    /// Unwraps the [`RecordingMatrix`], discarding the log and returning the wrapped structure.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    /// Performs the given [elementary row operation] on `self`, same as
+    /// [`MatrixReprOfLinSys::perform_elem_row_op`][crate::MatrixReprOfLinSys::perform_elem_row_op].
+    ///
+    /// [elementary row operation]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    pub fn perform_elem_row_op<O>(&mut self, o: O) -> Result<(), O::Error>
+    where
+        O: ElemRowOp<Self>,
+    {
+        o.perform(self)
+    }
+}
+
+impl<T, M> RecordingMatrix<T, M>
+where
+    T: Clone,
+{
+    /// Re-applies the captured sequence of [elementary row operations] to `other`, a
+    /// different structure of compatible shape.
+    ///
+    /// [elementary row operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+    pub fn replay<'s, M2>(&'s self, other: &mut M2) -> ReplayResult<'s, T, M2>
+    where
+        RowXchg: ElemRowOp<M2>,
+        RowMul<'s, T>: ElemRowOp<M2>,
+        RowAdd<'s, T>: ElemRowOp<M2>,
+    {
+        for record in &self.log {
+            match record {
+                RowOpRecord::RowXchg {
+                    row_zbi_1,
+                    row_zbi_2,
+                } => RowXchg {
+                    row_zbi_1: *row_zbi_1,
+                    row_zbi_2: *row_zbi_2,
+                }
+                .perform(other)
+                .map_err(ReplayError::RowXchg)?,
+                RowOpRecord::RowMul { row_zbi, factor } => RowMul {
+                    row_zbi: *row_zbi,
+                    factor,
+                }
+                .perform(other)
+                .map_err(ReplayError::RowMul)?,
+                RowOpRecord::RowAdd {
+                    inout_row_zbi,
+                    in_row_zbi,
+                    factor,
+                } => RowAdd {
+                    inout_row_zbi: *inout_row_zbi,
+                    in_row_zbi: *in_row_zbi,
+                    factor,
+                }
+                .perform(other)
+                .map_err(ReplayError::RowAdd)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, M> ElemRowOp<RecordingMatrix<T, M>> for RowXchg
+where
+    RowXchg: ElemRowOp<M>,
+{
+    type Error = <RowXchg as ElemRowOp<M>>::Error;
+
+    unsafe fn perform_unchecked(self, m: &mut RecordingMatrix<T, M>) {
+        let RowXchg {
+            row_zbi_1,
+            row_zbi_2,
+        } = self;
+        ElemRowOp::<M>::perform_unchecked(
+            RowXchg {
+                row_zbi_1,
+                row_zbi_2,
+            },
+            &mut m.inner,
+        );
+        m.log.push(RowOpRecord::RowXchg {
+            row_zbi_1,
+            row_zbi_2,
+        });
+    }
+
+    fn validate(&self, m: &RecordingMatrix<T, M>) -> Result<(), Self::Error> {
+        ElemRowOp::<M>::validate(self, &m.inner)
+    }
+}
+
+impl<'a, T, M> ElemRowOp<RecordingMatrix<T, M>> for RowMul<'a, T>
+where
+    T: Clone,
+    RowMul<'a, T>: ElemRowOp<M>,
+{
+    type Error = <RowMul<'a, T> as ElemRowOp<M>>::Error;
+
+    unsafe fn perform_unchecked(self, m: &mut RecordingMatrix<T, M>) {
+        let RowMul { row_zbi, factor } = self;
+        let record = RowOpRecord::RowMul {
+            row_zbi,
+            factor: factor.clone(),
+        };
+        ElemRowOp::<M>::perform_unchecked(RowMul { row_zbi, factor }, &mut m.inner);
+        m.log.push(record);
+    }
+
+    fn validate(&self, m: &RecordingMatrix<T, M>) -> Result<(), Self::Error> {
+        ElemRowOp::<M>::validate(self, &m.inner)
+    }
+}
+
+impl<'a, T, M> ElemRowOp<RecordingMatrix<T, M>> for RowAdd<'a, T>
+where
+    T: Clone,
+    RowAdd<'a, T>: ElemRowOp<M>,
+{
+    type Error = <RowAdd<'a, T> as ElemRowOp<M>>::Error;
+
+    unsafe fn perform_unchecked(self, m: &mut RecordingMatrix<T, M>) {
+        let RowAdd {
+            inout_row_zbi,
+            in_row_zbi,
+            factor,
+        } = self;
+        let record = RowOpRecord::RowAdd {
+            inout_row_zbi,
+            in_row_zbi,
+            factor: factor.clone(),
+        };
+        ElemRowOp::<M>::perform_unchecked(
+            RowAdd {
+                inout_row_zbi,
+                in_row_zbi,
+                factor,
+            },
+            &mut m.inner,
+        );
+        m.log.push(record);
+    }
+
+    fn validate(&self, m: &RecordingMatrix<T, M>) -> Result<(), Self::Error> {
+        ElemRowOp::<M>::validate(self, &m.inner)
+    }
+}
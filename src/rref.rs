@@ -0,0 +1,173 @@
+//! Module with the [Gauss–Jordan elimination] routine that reduces a [`MatrixReprOfLinSys`]
+//! to [reduced row echelon form].
+//!
+//! [Gauss–Jordan elimination]: https://en.wikipedia.org/wiki/Gaussian_elimination#Gauss%E2%80%93Jordan_elimination
+//! [reduced row echelon form]: https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form
+
+use crate::{
+    elem_row_ops::{RowAdd, RowMul, RowXchg},
+    MatrixReprOfLinSys,
+};
+use core::ops::{AddAssign, Div, Mul, MulAssign, Neg};
+use nalgebra::{Dim, RawStorageMut};
+use num_traits::{One, Zero};
+
+/// Picks the row, among those with a genuinely nonzero entry in the pivot column, with the
+/// largest-magnitude entry.
+///
+/// Every scalar type this crate's elimination currently runs over (`f32`/`f64` via
+/// `nalgebra::RealField`, and exact types such as `num_rational::Ratio`) is totally ordered,
+/// so there is no separate "first nonzero" fallback: picking by magnitude is always
+/// well-defined and is strictly better for numerical stability, so it is used unconditionally
+/// rather than gated behind a `RealField`-only bound.
+fn pick_pivot_row<T>(candidates: impl Iterator<Item = (usize, T)>) -> Option<usize>
+where
+    T: Clone + Zero + PartialOrd + Neg<Output = T>,
+{
+    let abs = |x: T| if x < T::zero() { -x } else { x };
+
+    candidates
+        .max_by(|(_, a), (_, b)| abs(a.clone()).partial_cmp(&abs(b.clone())).unwrap())
+        .map(|(i, _)| i)
+}
+
+impl<T, R, C, S> MatrixReprOfLinSys<T, R, C, S>
+where
+    T: Clone + Zero + One + PartialOrd + Neg<Output = T> + Div<Output = T> + AddAssign,
+    for<'b> T: Mul<&'b T, Output = T> + MulAssign<&'b T>,
+    R: Dim,
+    C: Dim,
+    S: RawStorageMut<T, R, C>,
+{
+    /// Reduces `self` to [reduced row echelon form] in place by driving the existing
+    /// [`RowXchg`], [`RowMul`], and [`RowAdd`] operations, and returns the zero-based
+    /// indices of the pivot columns, in the order they were found. The number of pivot
+    /// columns is the rank of the matrix.
+    ///
+    /// For numerical stability, the pivot row for a given column is chosen as the
+    /// remaining candidate row with the largest-magnitude entry in that column.
+    ///
+    /// # Note on `T`
+    ///
+    /// The bound on `T` is satisfiable by any type with a (possibly non-exact) `Div`, which
+    /// includes integer types such as `i32`. Integer division truncates, so running this on
+    /// an integer matrix silently produces a wrong reduction instead of a compile error or a
+    /// panic — use a floating-point or exact-rational scalar type (e.g. `f64` or
+    /// `num_rational::Ratio`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::matrix;
+    /// use nalgebra_linsys::MatrixReprOfLinSys as MRLS;
+    ///
+    /// // x₁ + 2x₂ = 3
+    /// // 2x₁ + 4x₂ = 7
+    /// let mut m = MRLS::new(matrix![
+    ///    1.0, 2.0, 3.0;
+    ///    2.0, 4.0, 7.0;
+    /// ]);
+    ///
+    /// let pivots = m.reduced_row_echelon_form();
+    ///
+    /// assert_eq!(pivots, vec![0, 2]);
+    /// assert_eq!(
+    ///    m.0,
+    ///    matrix![
+    ///      1.0, 2.0, 0.0;
+    ///      0.0, 0.0, 1.0;
+    /// ]);
+    /// ```
+    ///
+    /// [reduced row echelon form]: https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form
+    pub fn reduced_row_echelon_form(&mut self) -> Vec<usize> {
+        self.reduce_tracking_determinant().0
+    }
+
+    /// Reduces `self` to [reduced row echelon form], same as
+    /// [`reduced_row_echelon_form`][Self::reduced_row_echelon_form], additionally folding the
+    /// determinant-changing effect of each elementary operation used along the way into a
+    /// running accumulator: a [`RowXchg`] flips its sign, a [`RowMul`] that normalizes a
+    /// pivot to `1` multiplies it by that pivot (i.e. by the reciprocal of the `RowMul`'s
+    /// factor), and a [`RowAdd`] leaves it unchanged.
+    ///
+    /// Returns the pivot columns and the accumulator. The accumulator equals the determinant
+    /// only when every row obtained a pivot (i.e. the matrix is square and full rank); it is
+    /// exposed (rather than kept private) so that callers computing both a reduction and a
+    /// determinant/inverse from the same matrix can reuse this single pass instead of paying
+    /// for elimination twice.
+    ///
+    /// [reduced row echelon form]: https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form
+    pub fn reduce_tracking_determinant(&mut self) -> (Vec<usize>, T) {
+        let nrows = self.0.nrows();
+        let ncols = self.0.ncols();
+
+        let mut pivots = Vec::new();
+        let mut lead = 0;
+        let mut det_acc = T::one();
+
+        'rows: for r in 0..nrows {
+            let pivot_row = loop {
+                if lead >= ncols {
+                    break 'rows;
+                }
+
+                let candidates = (r..nrows)
+                    .map(|i| (i, self.0[(i, lead)].clone()))
+                    .filter(|(_, v)| !v.is_zero());
+
+                match pick_pivot_row(candidates) {
+                    Some(i) => break i,
+                    None => lead += 1,
+                }
+            };
+
+            if pivot_row != r {
+                self.perform_elem_row_op(RowXchg {
+                    row_zbi_1: r,
+                    row_zbi_2: pivot_row,
+                })
+                .unwrap();
+                det_acc = -det_acc;
+            }
+
+            let pivot = self.0[(r, lead)].clone();
+            det_acc *= &pivot;
+            self.perform_elem_row_op(RowMul {
+                row_zbi: r,
+                factor: &(T::one() / pivot),
+            })
+            .unwrap();
+
+            for i in 0..nrows {
+                if i == r {
+                    continue;
+                }
+                let factor = -self.0[(i, lead)].clone();
+                self.perform_elem_row_op(RowAdd {
+                    inout_row_zbi: i,
+                    in_row_zbi: r,
+                    factor: &factor,
+                })
+                .unwrap();
+            }
+
+            pivots.push(lead);
+            lead += 1;
+        }
+
+        (pivots, det_acc)
+    }
+
+    /// Consumes `self`, reduces it to [reduced row echelon form], and returns the reduced
+    /// matrix together with the zero-based indices of its pivot columns.
+    ///
+    /// See [`MatrixReprOfLinSys::reduced_row_echelon_form`] for the algorithm and an
+    /// example of the resulting form.
+    ///
+    /// [reduced row echelon form]: https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form
+    pub fn to_reduced_row_echelon_form(mut self) -> (Self, Vec<usize>) {
+        let pivots = self.reduced_row_echelon_form();
+        (self, pivots)
+    }
+}
@@ -0,0 +1,155 @@
+//! Module with [`SolutionSet`], the classified solution of a linear system, and the
+//! [`MatrixReprOfLinSys::solve`] method that produces it.
+//!
+//! [`MatrixReprOfLinSys::solve`]: crate::MatrixReprOfLinSys::solve
+
+use crate::MatrixReprOfLinSys;
+use core::ops::{Mul, MulAssign};
+use nalgebra::{allocator::Allocator, DVector, DefaultAllocator, Dim, RealField, Storage};
+
+/// The classified solution set of the linear system `Ax = b` [represented][MRLS] by a
+/// [`MatrixReprOfLinSys`] whose underlying matrix is the augmented matrix `[A | b]`.
+///
+/// [MRLS]: http://linear.ups.edu/html/definitions.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolutionSet<T> {
+    /// The system has exactly one solution.
+    Unique(DVector<T>),
+    /// The system has no solution, i.e. the augmented matrix has a pivot in its last
+    /// (augmented) column.
+    Inconsistent,
+    /// The system has infinitely many solutions. Every solution is `particular` plus some
+    /// linear combination of `null_space_basis`.
+    Infinite {
+        /// The solution obtained by setting every free variable to zero.
+        particular: DVector<T>,
+        /// One basis vector of the null space of `A` per free column.
+        null_space_basis: Vec<DVector<T>>,
+    },
+}
+
+impl<T, R, C, S> MatrixReprOfLinSys<T, R, C, S>
+where
+    T: RealField,
+    for<'b> T: Mul<&'b T, Output = T> + MulAssign<&'b T>,
+    R: Dim,
+    C: Dim,
+    S: Storage<T, R, C>,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Interprets `self` as the augmented matrix `[A | b]` of a linear system `Ax = b` and
+    /// returns its [classified solution set][SolutionSet].
+    ///
+    /// `self` is reduced to [reduced row echelon form][RREF] on an owned copy, so the
+    /// original matrix is left untouched. A pivot landing in the last (augmented) column
+    /// means the system is [inconsistent][SolutionSet::Inconsistent]; otherwise every free
+    /// (non-pivot) column among the unknowns contributes one vector to the null-space
+    /// basis, and the particular solution assigns every free variable `0`.
+    ///
+    /// # Example
+    ///
+    /// A system with exactly one solution:
+    ///
+    /// ```
+    /// use nalgebra::{matrix, dvector};
+    /// use nalgebra_linsys::{MatrixReprOfLinSys as MRLS, SolutionSet};
+    ///
+    /// // x₁ + 2x₂ = 3
+    /// // 4x₁ + 5x₂ = 6
+    /// let m = MRLS::new(matrix![
+    ///    1.0, 2.0, 3.0;
+    ///    4.0, 5.0, 6.0;
+    /// ]);
+    ///
+    /// assert_eq!(m.solve(), SolutionSet::Unique(dvector![-1.0, 2.0]));
+    /// ```
+    ///
+    /// A system with no solution, because the last (augmented) column ends up with a pivot:
+    ///
+    /// ```
+    /// use nalgebra::matrix;
+    /// use nalgebra_linsys::{MatrixReprOfLinSys as MRLS, SolutionSet};
+    ///
+    /// // x₁ + x₂ = 1
+    /// // x₁ + x₂ = 2
+    /// let m = MRLS::new(matrix![
+    ///    1.0, 1.0, 1.0;
+    ///    1.0, 1.0, 2.0;
+    /// ]);
+    ///
+    /// assert_eq!(m.solve(), SolutionSet::Inconsistent);
+    /// ```
+    ///
+    /// A system with infinitely many solutions, because `x₂` is a free variable:
+    ///
+    /// ```
+    /// use nalgebra::{matrix, dvector};
+    /// use nalgebra_linsys::{MatrixReprOfLinSys as MRLS, SolutionSet};
+    ///
+    /// // x₁ + x₂ = 2
+    /// // 2x₁ + 2x₂ = 4
+    /// let m = MRLS::new(matrix![
+    ///    1.0, 1.0, 2.0;
+    ///    2.0, 2.0, 4.0;
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///    m.solve(),
+    ///    SolutionSet::Infinite {
+    ///        particular: dvector![2.0, 0.0],
+    ///        null_space_basis: vec![dvector![-1.0, 1.0]],
+    ///    }
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has no columns: the augmented matrix `[A | b]` needs at least the `b`
+    /// column, so a zero-column matrix cannot represent a linear system.
+    ///
+    /// [RREF]: crate::MatrixReprOfLinSys::reduced_row_echelon_form
+    pub fn solve(&self) -> SolutionSet<T> {
+        let mut working = MatrixReprOfLinSys::new(self.0.clone_owned());
+        let pivot_cols = working.reduced_row_echelon_form();
+
+        let ncols = working.0.ncols();
+        assert!(
+            ncols >= 1,
+            "Unable to solve a linear system whose augmented matrix has no columns."
+        );
+        let last_col = ncols - 1;
+        let nvars = last_col;
+
+        if pivot_cols.contains(&last_col) {
+            return SolutionSet::Inconsistent;
+        }
+
+        let free_cols: Vec<usize> = (0..nvars).filter(|c| !pivot_cols.contains(c)).collect();
+
+        let mut particular = DVector::from_element(nvars, T::zero());
+        for (r, &col) in pivot_cols.iter().enumerate() {
+            particular[col] = working.0[(r, last_col)].clone();
+        }
+
+        if free_cols.is_empty() {
+            return SolutionSet::Unique(particular);
+        }
+
+        let null_space_basis = free_cols
+            .into_iter()
+            .map(|free_col| {
+                let mut basis_vec = DVector::from_element(nvars, T::zero());
+                basis_vec[free_col] = T::one();
+                for (r, &col) in pivot_cols.iter().enumerate() {
+                    basis_vec[col] = -working.0[(r, free_col)].clone();
+                }
+                basis_vec
+            })
+            .collect();
+
+        SolutionSet::Infinite {
+            particular,
+            null_space_basis,
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Module with implementations of [`ElemRowOp`] for [`nalgebra_sparse`] CSR and CSC matrices,
+//! so that elimination can run on sparse systems without densifying them first.
+//!
+//! [`RowMul`] only ever rescales stored values, so it mutates the compressed storage in
+//! place. [`RowXchg`] and [`RowAdd`] can change which entries are structurally stored (a
+//! `RowAdd` can introduce new nonzeros where the "in row" has entries the "inout row" lacks,
+//! and may let entries cancel to an exact zero), so both are implemented by disassembling the
+//! matrix, rebuilding the affected rows/columns, and reassembling it.
+//!
+//! [elementary row operations]: https://www.math.ucdavis.edu/~linear/old/notes3.pdf
+
+use crate::{
+    elem_row_op::ElemRowOp,
+    err::{BinaryRowIdxOutOfBoundsError, RowIdxOutOfBoundsError, SparseRowOpError},
+    row_add::RowAdd,
+    row_mul::RowMul,
+    row_xchg::RowXchg,
+};
+use core::ops::{AddAssign, Mul, MulAssign};
+use nalgebra_sparse::{csc::CscMatrix, csr::CsrMatrix};
+use num_traits::Zero;
+
+fn validate_binary(nrows: usize, i_1: usize, i_2: usize) -> Result<(), SparseRowOpError> {
+    use BinaryRowIdxOutOfBoundsError::*;
+
+    match (i_1, i_2) {
+        (i_1, i_2) if i_1 >= nrows && i_2 >= nrows => Err(BothIdcesOutOfBounds((i_1, i_2)).into()),
+        (i_1, _) if i_1 >= nrows => Err(FirstIdxOutOfBounds((i_1, i_2)).into()),
+        (_, i_2) if i_2 >= nrows => Err(SecondIdxOutOfBounds((i_1, i_2)).into()),
+        _ => Ok(()),
+    }
+}
+
+fn validate_unary(nrows: usize, i: usize) -> Result<(), SparseRowOpError> {
+    if i >= nrows {
+        Err(RowIdxOutOfBoundsError(i).into())
+    } else {
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// CSR
+// ---------------------------------------------------------------------------------------
+
+/// # Example
+///
+/// ```
+/// use nalgebra_sparse::csr::CsrMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowMul};
+///
+/// // [1.0, 0.0]
+/// // [0.0, 2.0]
+/// let mut m = CsrMatrix::try_from_csr_data(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0])
+///     .unwrap();
+///
+/// RowMul { row_zbi: 0, factor: &3.0 }.perform(&mut m).unwrap();
+///
+/// assert_eq!(m.values(), &[3.0, 2.0]);
+/// ```
+impl<'a, T> ElemRowOp<CsrMatrix<T>> for RowMul<'a, T>
+where
+    T: Clone + MulAssign<&'a T>,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CsrMatrix<T>) {
+        let RowMul { row_zbi: i, factor } = self;
+        for value in m.row_mut(i).values_mut() {
+            *value *= factor;
+        }
+    }
+
+    fn validate(&self, m: &CsrMatrix<T>) -> Result<(), Self::Error> {
+        validate_unary(m.nrows(), self.row_zbi)
+    }
+}
+
+/// # Example
+///
+/// ```
+/// use nalgebra_sparse::csr::CsrMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowXchg};
+///
+/// // [1.0, 0.0]
+/// // [0.0, 2.0]
+/// let mut m = CsrMatrix::try_from_csr_data(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0])
+///     .unwrap();
+///
+/// RowXchg { row_zbi_1: 0, row_zbi_2: 1 }.perform(&mut m).unwrap();
+///
+/// assert_eq!(m.values(), &[2.0, 1.0]);
+/// ```
+impl<T> ElemRowOp<CsrMatrix<T>> for RowXchg
+where
+    T: Clone,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CsrMatrix<T>) {
+        let RowXchg { row_zbi_1: i_1, row_zbi_2: i_2 } = self;
+        if i_1 == i_2 {
+            return;
+        }
+
+        let nrows = m.nrows();
+        let ncols = m.ncols();
+        let (row_offsets, col_indices, values) = m.clone().disassemble();
+
+        let row_range = |r: usize| row_offsets[r]..row_offsets[r + 1];
+
+        let mut new_row_offsets = Vec::with_capacity(row_offsets.len());
+        let mut new_col_indices = Vec::with_capacity(col_indices.len());
+        let mut new_values = Vec::with_capacity(values.len());
+        new_row_offsets.push(0);
+
+        for r in 0..nrows {
+            let range = if r == i_1 {
+                row_range(i_2)
+            } else if r == i_2 {
+                row_range(i_1)
+            } else {
+                row_range(r)
+            };
+            new_col_indices.extend_from_slice(&col_indices[range.clone()]);
+            new_values.extend(values[range].iter().cloned());
+            new_row_offsets.push(new_col_indices.len());
+        }
+
+        *m = CsrMatrix::try_from_csr_data(nrows, ncols, new_row_offsets, new_col_indices, new_values)
+            .expect("row exchange preserves a valid CSR structure");
+    }
+
+    fn validate(&self, m: &CsrMatrix<T>) -> Result<(), Self::Error> {
+        validate_binary(m.nrows(), self.row_zbi_1, self.row_zbi_2)
+    }
+}
+
+/// # Example
+///
+/// Cancels the `(0, 0)` entry to an exact structural zero while introducing a new
+/// structural nonzero at `(0, 1)`.
+///
+/// ```
+/// use nalgebra_sparse::csr::CsrMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowAdd};
+///
+/// //  2.0  0.0  3.0
+/// // -1.0  5.0  0.0
+/// let mut m = CsrMatrix::try_from_csr_data(
+///     2, 3,
+///     vec![0, 2, 4],
+///     vec![0, 2, 0, 1],
+///     vec![2.0, 3.0, -1.0, 5.0],
+/// ).unwrap();
+///
+/// RowAdd { inout_row_zbi: 0, in_row_zbi: 1, factor: &2.0 }.perform(&mut m).unwrap();
+///
+/// //  0.0  10.0  3.0
+/// // -1.0   5.0  0.0
+/// let (row_offsets, col_indices, values) = m.disassemble();
+/// assert_eq!(row_offsets, vec![0, 2, 4]);
+/// assert_eq!(col_indices, vec![1, 2, 0, 1]);
+/// assert_eq!(values, vec![10.0, 3.0, -1.0, 5.0]);
+/// ```
+impl<'a, T> ElemRowOp<CsrMatrix<T>> for RowAdd<'a, T>
+where
+    T: Clone + Zero + AddAssign + Mul<&'a T, Output = T>,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CsrMatrix<T>) {
+        let RowAdd {
+            inout_row_zbi: i_1,
+            in_row_zbi: i_2,
+            factor,
+        } = self;
+
+        let nrows = m.nrows();
+        let ncols = m.ncols();
+        let (row_offsets, col_indices, values) = m.clone().disassemble();
+
+        let row_entries = |r: usize| {
+            let range = row_offsets[r]..row_offsets[r + 1];
+            col_indices[range.clone()]
+                .iter()
+                .copied()
+                .zip(values[range].iter().cloned())
+        };
+
+        let mut new_row_offsets = Vec::with_capacity(row_offsets.len());
+        let mut new_col_indices = Vec::with_capacity(col_indices.len());
+        let mut new_values = Vec::with_capacity(values.len());
+        new_row_offsets.push(0);
+
+        for r in 0..nrows {
+            if r == i_1 {
+                let mut merged: Vec<(usize, T)> = row_entries(i_1).collect();
+                for (col, in_val) in row_entries(i_2) {
+                    let added = in_val * factor;
+                    match merged.iter().position(|&(c, _)| c == col) {
+                        Some(pos) => merged[pos].1 += added,
+                        None => merged.push((col, added)),
+                    }
+                }
+                merged.retain(|(_, v)| !v.is_zero());
+                merged.sort_by_key(|&(col, _)| col);
+                for (col, val) in merged {
+                    new_col_indices.push(col);
+                    new_values.push(val);
+                }
+            } else {
+                for (col, val) in row_entries(r) {
+                    new_col_indices.push(col);
+                    new_values.push(val);
+                }
+            }
+            new_row_offsets.push(new_col_indices.len());
+        }
+
+        *m = CsrMatrix::try_from_csr_data(nrows, ncols, new_row_offsets, new_col_indices, new_values)
+            .expect("row addition preserves a valid CSR structure");
+    }
+
+    fn validate(&self, m: &CsrMatrix<T>) -> Result<(), Self::Error> {
+        validate_binary(m.nrows(), self.inout_row_zbi, self.in_row_zbi)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// CSC
+// ---------------------------------------------------------------------------------------
+//
+// Unlike CSR, CSC stores entries column-major, so a single row's entries are scattered one
+// per column; every operation below has to walk the whole matrix, one column at a time.
+
+/// # Example
+///
+/// ```
+/// use nalgebra_sparse::csc::CscMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowMul};
+///
+/// // [1.0, 0.0]
+/// // [0.0, 2.0]
+/// let mut m = CscMatrix::try_from_csc_data(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0])
+///     .unwrap();
+///
+/// RowMul { row_zbi: 0, factor: &3.0 }.perform(&mut m).unwrap();
+///
+/// assert_eq!(m.values(), &[3.0, 2.0]);
+/// ```
+impl<'a, T> ElemRowOp<CscMatrix<T>> for RowMul<'a, T>
+where
+    T: Clone + MulAssign<&'a T>,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CscMatrix<T>) {
+        let RowMul { row_zbi: i, factor } = self;
+        for c in 0..m.ncols() {
+            let mut col = m.col_mut(c);
+            if let Some(pos) = col.row_indices().iter().position(|&r| r == i) {
+                col.values_mut()[pos] *= factor;
+            }
+        }
+    }
+
+    fn validate(&self, m: &CscMatrix<T>) -> Result<(), Self::Error> {
+        validate_unary(m.nrows(), self.row_zbi)
+    }
+}
+
+/// # Example
+///
+/// ```
+/// use nalgebra_sparse::csc::CscMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowXchg};
+///
+/// // [1.0, 0.0]
+/// // [0.0, 2.0]
+/// let mut m = CscMatrix::try_from_csc_data(2, 2, vec![0, 1, 2], vec![0, 1], vec![1.0, 2.0])
+///     .unwrap();
+///
+/// RowXchg { row_zbi_1: 0, row_zbi_2: 1 }.perform(&mut m).unwrap();
+///
+/// // [0.0, 2.0]
+/// // [1.0, 0.0]
+/// let (_, row_indices, values) = m.disassemble();
+/// assert_eq!(row_indices, vec![1, 0]);
+/// assert_eq!(values, vec![1.0, 2.0]);
+/// ```
+impl<T> ElemRowOp<CscMatrix<T>> for RowXchg
+where
+    T: Clone,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CscMatrix<T>) {
+        let RowXchg { row_zbi_1: i_1, row_zbi_2: i_2 } = self;
+        if i_1 == i_2 {
+            return;
+        }
+
+        let nrows = m.nrows();
+        let ncols = m.ncols();
+        let (col_offsets, row_indices, values) = m.clone().disassemble();
+
+        let mut new_col_offsets = Vec::with_capacity(col_offsets.len());
+        let mut new_row_indices = Vec::with_capacity(row_indices.len());
+        let mut new_values = Vec::with_capacity(values.len());
+        new_col_offsets.push(0);
+
+        for c in 0..ncols {
+            let range = col_offsets[c]..col_offsets[c + 1];
+            let mut entries: Vec<(usize, T)> = range
+                .map(|k| {
+                    let r = row_indices[k];
+                    let r = if r == i_1 {
+                        i_2
+                    } else if r == i_2 {
+                        i_1
+                    } else {
+                        r
+                    };
+                    (r, values[k].clone())
+                })
+                .collect();
+            entries.sort_by_key(|&(r, _)| r);
+            for (r, v) in entries {
+                new_row_indices.push(r);
+                new_values.push(v);
+            }
+            new_col_offsets.push(new_row_indices.len());
+        }
+
+        *m = CscMatrix::try_from_csc_data(nrows, ncols, new_col_offsets, new_row_indices, new_values)
+            .expect("row exchange preserves a valid CSC structure");
+    }
+
+    fn validate(&self, m: &CscMatrix<T>) -> Result<(), Self::Error> {
+        validate_binary(m.nrows(), self.row_zbi_1, self.row_zbi_2)
+    }
+}
+
+/// # Example
+///
+/// Cancels the `(0, 0)` entry to an exact structural zero while introducing a new
+/// structural nonzero at `(0, 1)` — the same elimination as the [`CsrMatrix`] example above,
+/// on the column-major equivalent of the same matrix.
+///
+/// ```
+/// use nalgebra_sparse::csc::CscMatrix;
+/// use nalgebra_linsys::elem_row_ops::{ElemRowOp, RowAdd};
+///
+/// //  2.0  0.0  3.0
+/// // -1.0  5.0  0.0
+/// let mut m = CscMatrix::try_from_csc_data(
+///     2, 3,
+///     vec![0, 2, 3, 4],
+///     vec![0, 1, 1, 0],
+///     vec![2.0, -1.0, 5.0, 3.0],
+/// ).unwrap();
+///
+/// RowAdd { inout_row_zbi: 0, in_row_zbi: 1, factor: &2.0 }.perform(&mut m).unwrap();
+///
+/// //  0.0  10.0  3.0
+/// // -1.0   5.0  0.0
+/// let (col_offsets, row_indices, values) = m.disassemble();
+/// assert_eq!(col_offsets, vec![0, 1, 3, 4]);
+/// assert_eq!(row_indices, vec![1, 0, 1, 0]);
+/// assert_eq!(values, vec![-1.0, 10.0, 5.0, 3.0]);
+/// ```
+impl<'a, T> ElemRowOp<CscMatrix<T>> for RowAdd<'a, T>
+where
+    T: Clone + Zero + AddAssign + Mul<&'a T, Output = T>,
+{
+    type Error = SparseRowOpError;
+
+    unsafe fn perform_unchecked(self, m: &mut CscMatrix<T>) {
+        let RowAdd {
+            inout_row_zbi: i_1,
+            in_row_zbi: i_2,
+            factor,
+        } = self;
+
+        let nrows = m.nrows();
+        let ncols = m.ncols();
+        let (col_offsets, row_indices, values) = m.clone().disassemble();
+
+        let mut new_col_offsets = Vec::with_capacity(col_offsets.len());
+        let mut new_row_indices = Vec::with_capacity(row_indices.len());
+        let mut new_values = Vec::with_capacity(values.len());
+        new_col_offsets.push(0);
+
+        for c in 0..ncols {
+            let range = col_offsets[c]..col_offsets[c + 1];
+            let mut entries: Vec<(usize, T)> = range
+                .map(|k| (row_indices[k], values[k].clone()))
+                .collect();
+
+            if let Some(in_val) = entries
+                .iter()
+                .find(|&&(r, _)| r == i_2)
+                .map(|(_, v)| v.clone())
+            {
+                let added = in_val * factor;
+                match entries.iter().position(|&(r, _)| r == i_1) {
+                    Some(pos) => entries[pos].1 += added,
+                    None => entries.push((i_1, added)),
+                }
+                entries.retain(|(_, v)| !v.is_zero());
+                entries.sort_by_key(|&(r, _)| r);
+            }
+
+            for (r, v) in entries {
+                new_row_indices.push(r);
+                new_values.push(v);
+            }
+            new_col_offsets.push(new_row_indices.len());
+        }
+
+        *m = CscMatrix::try_from_csc_data(nrows, ncols, new_col_offsets, new_row_indices, new_values)
+            .expect("row addition preserves a valid CSC structure");
+    }
+
+    fn validate(&self, m: &CscMatrix<T>) -> Result<(), Self::Error> {
+        validate_binary(m.nrows(), self.inout_row_zbi, self.in_row_zbi)
+    }
+}